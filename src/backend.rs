@@ -0,0 +1,99 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Options threaded through every backend's install/metadata calls.
+#[derive(Debug, Clone)]
+pub struct InstallOptions {
+    /// Skip the confirmation prompt before writing to the filesystem.
+    pub noconfirm: bool,
+    /// 0 = errors only, 1 = normal (default), 2+ = verbose.
+    pub verbosity: u8,
+    /// Report what would be written without touching the filesystem.
+    pub dry_run: bool,
+}
+
+impl Default for InstallOptions {
+    fn default() -> Self {
+        InstallOptions {
+            noconfirm: false,
+            verbosity: 1,
+            dry_run: false,
+        }
+    }
+}
+
+/// The single error type every backend reports through, so callers in
+/// `main` never need to match on backend-specific failure types.
+#[derive(Debug)]
+pub enum InstallError {
+    Io(std::io::Error),
+    Parse(String),
+    Process(String),
+    HashMismatch {
+        path: String,
+        expected: String,
+        actual: String,
+    },
+    Other(String),
+}
+
+impl fmt::Display for InstallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InstallError::Io(e) => write!(f, "I/O error: {}", e),
+            InstallError::Parse(msg) => write!(f, "Failed to parse package metadata: {}", msg),
+            InstallError::Process(msg) => write!(f, "External command failed: {}", msg),
+            InstallError::HashMismatch {
+                path,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "Hash mismatch for {}: expected {}, got {}",
+                path, expected, actual
+            ),
+            InstallError::Other(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for InstallError {}
+
+impl From<std::io::Error> for InstallError {
+    fn from(e: std::io::Error) -> Self {
+        InstallError::Io(e)
+    }
+}
+
+/// Backend-agnostic view of a package's declared metadata.
+///
+/// Callers today only consult `name` (and eopkg's dependency resolver reads
+/// `runtime_dependencies` straight off the parsed metadata instead), but the
+/// rest mirrors what every backend is already able to report.
+#[allow(dead_code)]
+#[derive(Debug, Default, Clone)]
+pub struct PackageInfo {
+    pub name: String,
+    pub summary: String,
+    pub description: String,
+    pub architecture: String,
+    pub runtime_dependencies: Vec<String>,
+}
+
+/// A pluggable installer for one package format (`.deb`, `.eopkg`, `.rpm`, ...).
+///
+/// `main` probes every registered backend with `can_handle` instead of
+/// string-matching file extensions, so adding a new format only means
+/// implementing this trait and registering it.
+pub trait PackageBackend {
+    /// Whether this backend knows how to install `path`.
+    fn can_handle(&self, path: &Path) -> bool;
+
+    /// Installs `pkg` and returns every absolute path written to disk, in
+    /// the order they were written, so the caller can commit them to the
+    /// installed-file manifest.
+    fn install(&self, pkg: &Path, opts: &InstallOptions) -> Result<Vec<PathBuf>, InstallError>;
+
+    /// Reads `pkg`'s declared metadata without installing it.
+    fn metadata(&self, pkg: &Path) -> Result<PackageInfo, InstallError>;
+}
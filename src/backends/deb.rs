@@ -0,0 +1,82 @@
+use crate::backend::{InstallError, InstallOptions, PackageBackend, PackageInfo};
+use crate::output;
+use std::io::{BufRead, BufReader};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::thread;
+
+pub struct DebBackend;
+
+impl PackageBackend for DebBackend {
+    fn can_handle(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "deb")
+    }
+
+    fn install(&self, pkg: &Path, opts: &InstallOptions) -> Result<Vec<PathBuf>, InstallError> {
+        if opts.dry_run {
+            output::info(
+                opts,
+                format!(
+                    "Would hand off installation of {} to deb/main.py \
+                     (its file list isn't known ahead of time)",
+                    pkg.display()
+                ),
+            );
+            return Ok(Vec::new());
+        }
+
+        if !output::confirm(opts, &format!("Install package '{}'?", pkg.display())) {
+            return Err(InstallError::Other("install aborted by user".to_string()));
+        }
+
+        let child = Command::new("python3")
+            .arg("deb/main.py")
+            .arg("install")
+            .arg(pkg)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|e| InstallError::Process(format!("failed to spawn deb/main.py: {}", e)))?;
+
+        let status = stream_output(child)
+            .map_err(|e| InstallError::Process(format!("deb/main.py failed: {}", e)))?;
+        if !status.success() {
+            return Err(InstallError::Process(
+                "deb/main.py exited with a non-zero status".to_string(),
+            ));
+        }
+
+        // deb/main.py does not report back which paths it wrote, so there's
+        // nothing to hand the caller for the installed-file manifest yet.
+        Ok(Vec::new())
+    }
+
+    fn metadata(&self, _pkg: &Path) -> Result<PackageInfo, InstallError> {
+        Err(InstallError::Other(
+            "metadata inspection is not implemented for .deb packages".to_string(),
+        ))
+    }
+}
+
+fn stream_output(mut command: std::process::Child) -> std::io::Result<std::process::ExitStatus> {
+    let stdout = command.stdout.take().unwrap();
+    let stdout_reader = BufReader::new(stdout);
+    let stdout_thread = thread::spawn(move || {
+        for line in stdout_reader.lines().map_while(Result::ok) {
+            println!("{}", line);
+        }
+    });
+
+    let stderr = command.stderr.take().unwrap();
+    let stderr_reader = BufReader::new(stderr);
+    let stderr_thread = thread::spawn(move || {
+        for line in stderr_reader.lines().map_while(Result::ok) {
+            eprintln!("{}", line);
+        }
+    });
+
+    stdout_thread.join().unwrap();
+    stderr_thread.join().unwrap();
+
+    command.wait()
+}
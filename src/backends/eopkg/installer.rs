@@ -0,0 +1,176 @@
+use super::parser::{parse_files_xml, parse_metadata_xml, File as XmlFile};
+use crate::backend::{InstallError, InstallOptions};
+use crate::output;
+use sha1::{Digest, Sha1};
+use std::collections::HashMap;
+use std::fs::{self, File as FsFile};
+use std::io::{self, BufReader, Write};
+use std::os::unix::fs::{symlink, PermissionsExt};
+use std::path::{Path, PathBuf};
+use tar::{Archive, EntryType};
+use xz2::read::XzDecoder;
+
+/// Installs an already-unpacked `.eopkg` directory (`metadata.xml` /
+/// `files.xml` / `install.tar.xz`) and returns every path written, in
+/// install order, so the caller can commit them to the manifest.
+pub fn install_package(
+    package_path: &Path,
+    opts: &InstallOptions,
+) -> Result<Vec<PathBuf>, InstallError> {
+    // A bare package name resolved against a repository is downloaded as a
+    // single archive file; installing from it requires extracting it first,
+    // which isn't implemented yet, so fail clearly here instead of further
+    // down when metadata.xml can't be found inside it.
+    if !package_path.is_dir() {
+        return Err(InstallError::Other(format!(
+            "'{}' is not an extracted eopkg package directory \
+             (fetching and extracting .eopkg archives isn't implemented yet)",
+            package_path.display()
+        )));
+    }
+
+    let metadata_path = package_path.join("metadata.xml");
+    let files_path = package_path.join("files.xml");
+    let tar_path = package_path.join("install.tar.xz");
+
+    let metadata = parse_metadata_xml(metadata_path.to_str().unwrap())
+        .map_err(|e| InstallError::Parse(e.to_string()))?;
+    output::info(opts, format!("Installing package: {}", metadata.Name));
+    output::verbose(opts, format!("Description: {}", metadata.Description));
+    output::verbose(opts, format!("Architecture: {}", metadata.Architecture));
+
+    let files = parse_files_xml(files_path.to_str().unwrap())
+        .map_err(|e| InstallError::Parse(e.to_string()))?;
+    let expected_hashes: HashMap<&str, &XmlFile> =
+        files.File.iter().map(|f| (f.Path.as_str(), f)).collect();
+
+    if opts.dry_run {
+        output::info(opts, "Would write the following files:");
+        for file in &files.File {
+            println!("  /{}", file.Path);
+        }
+        return Ok(Vec::new());
+    }
+
+    if !output::confirm(
+        opts,
+        &format!("Install package '{}'?", metadata.Name),
+    ) {
+        return Err(InstallError::Other("install aborted by user".to_string()));
+    }
+
+    let tar_file = FsFile::open(&tar_path)?;
+    let decompressed = XzDecoder::new(BufReader::new(tar_file));
+    let mut archive = Archive::new(decompressed);
+
+    let mut installed_paths: Vec<PathBuf> = Vec::new();
+
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        let entry_path = entry.path()?.into_owned();
+        let relative_path = entry_path.to_string_lossy().to_string();
+        let destination = Path::new("/").join(&entry_path);
+
+        let entry_type = entry.header().entry_type();
+
+        if entry_type == EntryType::Directory {
+            // Only directories this install creates count as written, so
+            // removing the package never deletes one that was already there.
+            if !destination.is_dir() {
+                fs::create_dir_all(&destination)?;
+                installed_paths.push(destination);
+            }
+            continue;
+        }
+
+        if let Some(parent) = destination.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        if entry_type == EntryType::Symlink {
+            // Materialize the link itself rather than copying through it,
+            // so shared libraries and their sonames stay linked instead of
+            // turning into duplicate files on disk.
+            let link_target = entry.link_name()?.ok_or_else(|| {
+                InstallError::Other(format!("symlink entry {} has no target", relative_path))
+            })?;
+            if destination.symlink_metadata().is_ok() {
+                fs::remove_file(&destination)?;
+            }
+            symlink(&link_target, &destination)?;
+            installed_paths.push(destination);
+            continue;
+        }
+
+        // Stream the entry straight into its destination file while
+        // hashing it incrementally, instead of unpacking the whole
+        // archive again for every entry and re-reading each file in full
+        // afterwards.
+        let mode = entry.header().mode()?;
+        let mut writer = HashingWriter {
+            inner: FsFile::create(&destination)?,
+            hasher: Sha1::new(),
+        };
+        io::copy(&mut entry, &mut writer)?;
+        let calculated_hash = format!("{:x}", writer.hasher.finalize());
+
+        if let Some(expected) = expected_hashes.get(relative_path.as_str()) {
+            if calculated_hash != expected.Hash {
+                rollback(&installed_paths, &destination);
+                return Err(InstallError::HashMismatch {
+                    path: relative_path,
+                    expected: expected.Hash.clone(),
+                    actual: calculated_hash,
+                });
+            }
+        }
+
+        // io::copy doesn't preserve the tar header's permission bits, so
+        // installed binaries/scripts would otherwise lose their executable
+        // bit.
+        fs::set_permissions(&destination, fs::Permissions::from_mode(mode))?;
+
+        installed_paths.push(destination);
+    }
+
+    output::info(opts, "Package installed successfully!");
+
+    Ok(installed_paths)
+}
+
+/// Removes everything written so far this run, in reverse order, so a hash
+/// mismatch aborts the whole install rather than leaving a partially
+/// extracted package behind.
+fn rollback(installed_paths: &[PathBuf], failed_destination: &Path) {
+    let _ = fs::remove_file(failed_destination);
+    for path in installed_paths.iter().rev() {
+        // `is_dir` follows symlinks, so a symlink pointing at a directory
+        // would otherwise be mistaken for one and removed with
+        // `remove_dir` instead of unlinked.
+        let is_real_dir = fs::symlink_metadata(path)
+            .map(|m| m.file_type().is_dir())
+            .unwrap_or(false);
+        let _ = if is_real_dir {
+            fs::remove_dir(path)
+        } else {
+            fs::remove_file(path)
+        };
+    }
+}
+
+struct HashingWriter<W> {
+    inner: W,
+    hasher: Sha1,
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        self.hasher.update(&buf[..n]);
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
@@ -0,0 +1,106 @@
+mod installer;
+mod parser;
+mod resolver;
+
+use crate::backend::{InstallError, InstallOptions, PackageBackend, PackageInfo};
+use parser::parse_metadata_xml;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+pub use resolver::resolve_install_order;
+
+/// Whether `path` looks like something this backend can introspect for
+/// `RuntimeDependencies` (same shape `can_handle` checks for).
+pub fn looks_like_eopkg(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "eopkg") || path.join("metadata.xml").exists()
+}
+
+/// Builds the dependency-ordered install plan for `initial_path`'s package:
+/// parses its `metadata.xml`, and transitively every `RuntimeDependencies`
+/// entry's `metadata.xml` (fetching from the configured repositories every
+/// dependency that isn't already installed), then hands the whole catalog to
+/// [`resolve_install_order`].
+///
+/// Returns `(name, package_path)` pairs in the order they must be installed.
+/// A requested package that itself can't be parsed as eopkg metadata (e.g. a
+/// bare install path with no `metadata.xml`) is returned as a single-entry
+/// plan rather than an error, so callers can fall back to installing it
+/// as-is.
+pub fn resolve_install_plan(
+    initial_path: &Path,
+    initial_name: &str,
+) -> Result<Vec<(String, PathBuf)>, InstallError> {
+    let mut catalog: HashMap<String, parser::Metadata> = HashMap::new();
+    let mut paths: HashMap<String, PathBuf> = HashMap::new();
+    let mut queue = vec![(initial_name.to_string(), initial_path.to_path_buf())];
+
+    while let Some((name, path)) = queue.pop() {
+        if paths.contains_key(&name) {
+            continue;
+        }
+
+        let metadata_path = path.join("metadata.xml");
+        let Ok(metadata) = parse_metadata_xml(metadata_path.to_str().unwrap()) else {
+            paths.insert(name, path);
+            continue;
+        };
+
+        for dependency in &metadata.runtime_dependencies {
+            if !paths.contains_key(dependency) && crate::manifest::load(dependency).is_err() {
+                let dependency_path = crate::repository::fetch_package(dependency)
+                    .map_err(InstallError::Other)?;
+                queue.push((dependency.clone(), dependency_path));
+            }
+        }
+
+        paths.insert(name.clone(), path);
+        catalog.insert(name, metadata);
+    }
+
+    let order = resolve_install_order(&catalog, &[initial_name.to_string()])
+        .map_err(InstallError::Other)?;
+
+    Ok(order
+        .into_iter()
+        .filter_map(|name| paths.get(&name).cloned().map(|path| (name, path)))
+        .collect())
+}
+
+pub struct EopkgBackend;
+
+impl PackageBackend for EopkgBackend {
+    fn can_handle(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "eopkg")
+            || path.join("metadata.xml").exists()
+    }
+
+    fn install(&self, pkg: &Path, opts: &InstallOptions) -> Result<Vec<PathBuf>, InstallError> {
+        installer::install_package(pkg, opts)
+    }
+
+    fn metadata(&self, pkg: &Path) -> Result<PackageInfo, InstallError> {
+        // A bare package name resolved against a repository is downloaded as
+        // a single archive file; this backend only knows how to read an
+        // already-extracted package directory, so fail clearly here instead
+        // of further down when metadata.xml can't be found inside it.
+        if !pkg.is_dir() {
+            return Err(InstallError::Other(format!(
+                "'{}' is not an extracted eopkg package directory \
+                 (fetching and extracting .eopkg archives isn't implemented yet)",
+                pkg.display()
+            )));
+        }
+
+        let metadata_path = pkg.join("metadata.xml");
+        let metadata = parse_metadata_xml(metadata_path.to_str().unwrap())
+            .map_err(|e| InstallError::Parse(e.to_string()))?;
+
+        Ok(PackageInfo {
+            name: metadata.Name,
+            summary: metadata.Summary,
+            description: metadata.Description,
+            architecture: metadata.Architecture,
+            runtime_dependencies: metadata.runtime_dependencies,
+        })
+    }
+}
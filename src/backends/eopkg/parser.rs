@@ -0,0 +1,66 @@
+use quick_xml::de::from_str;
+use serde::Deserialize;
+use std::fs;
+
+// Field names mirror the eopkg XML tags exactly (no `rename`s needed), so
+// they intentionally don't follow Rust's snake_case convention here. Only
+// `Path` and `Hash` are consulted during install; the rest round-trip the
+// full files.xml record for whoever needs them next.
+#[allow(non_snake_case, dead_code)]
+#[derive(Debug, Deserialize)]
+pub struct File {
+    pub Path: String,
+    pub Type: String,
+    pub Size: u64,
+    pub Uid: u32,
+    pub Gid: u32,
+    pub Mode: String,
+    pub Hash: String,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct Files {
+    pub File: Vec<File>,
+}
+
+#[allow(non_snake_case)]
+#[derive(Debug, Deserialize)]
+pub struct Metadata {
+    pub Name: String,
+    pub Summary: String,
+    pub Description: String,
+    pub Architecture: String,
+    #[serde(
+        rename = "RuntimeDependencies",
+        default,
+        deserialize_with = "deserialize_runtime_dependencies"
+    )]
+    pub runtime_dependencies: Vec<String>,
+}
+
+/// `<RuntimeDependencies>` wraps a list of `<Dependency>name</Dependency>`
+/// elements; flatten that straight into `Vec<String>` so callers don't have
+/// to reach through an extra wrapper type.
+fn deserialize_runtime_dependencies<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Debug, Deserialize, Default)]
+    struct RuntimeDependencies {
+        #[serde(rename = "Dependency", default)]
+        dependency: Vec<String>,
+    }
+
+    Ok(RuntimeDependencies::deserialize(deserializer)?.dependency)
+}
+
+pub fn parse_files_xml(file_path: &str) -> Result<Files, String> {
+    let content = fs::read_to_string(file_path).map_err(|e| e.to_string())?;
+    from_str(&content).map_err(|e| e.to_string())
+}
+
+pub fn parse_metadata_xml(file_path: &str) -> Result<Metadata, String> {
+    let content = fs::read_to_string(file_path).map_err(|e| e.to_string())?;
+    from_str(&content).map_err(|e| e.to_string())
+}
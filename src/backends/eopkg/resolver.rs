@@ -0,0 +1,63 @@
+use super::parser::Metadata;
+use crate::manifest;
+use std::collections::{HashMap, HashSet};
+
+/// Resolves `requested` packages and their `RuntimeDependencies` into a
+/// flat, topologically ordered install list (dependencies before the
+/// packages that need them).
+///
+/// `catalog` maps a package name to its already-parsed `metadata.xml`.
+/// Packages already present in the installed-file manifest are treated as
+/// satisfied leaves and are skipped rather than recursed into.
+pub fn resolve_install_order(
+    catalog: &HashMap<String, Metadata>,
+    requested: &[String],
+) -> Result<Vec<String>, String> {
+    let mut order = Vec::new();
+    let mut visited = HashSet::new();
+    let mut on_stack = HashSet::new();
+
+    for name in requested {
+        visit(name, catalog, &mut visited, &mut on_stack, &mut order)?;
+    }
+
+    Ok(order)
+}
+
+/// Depth-first post-order walk: recurse into every dependency before
+/// emitting the current node, so the returned order can be installed
+/// front-to-back.
+fn visit(
+    name: &str,
+    catalog: &HashMap<String, Metadata>,
+    visited: &mut HashSet<String>,
+    on_stack: &mut HashSet<String>,
+    order: &mut Vec<String>,
+) -> Result<(), String> {
+    if visited.contains(name) {
+        return Ok(());
+    }
+
+    if manifest::load(name).is_ok() {
+        // Already on disk: a satisfied leaf, nothing left to resolve.
+        visited.insert(name.to_string());
+        return Ok(());
+    }
+
+    if on_stack.contains(name) {
+        return Err(format!("Dependency cycle detected at package '{}'", name));
+    }
+    on_stack.insert(name.to_string());
+
+    if let Some(metadata) = catalog.get(name) {
+        for dependency in &metadata.runtime_dependencies {
+            visit(dependency, catalog, visited, on_stack, order)?;
+        }
+    }
+
+    on_stack.remove(name);
+    visited.insert(name.to_string());
+    order.push(name.to_string());
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+pub mod deb;
+pub mod eopkg;
+pub mod rpm;
@@ -0,0 +1,166 @@
+use crate::backend::{InstallError, InstallOptions, PackageBackend, PackageInfo};
+use crate::output;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+
+pub struct RpmBackend;
+
+impl PackageBackend for RpmBackend {
+    fn can_handle(&self, path: &Path) -> bool {
+        path.extension().is_some_and(|ext| ext == "rpm")
+    }
+
+    fn install(&self, pkg: &Path, opts: &InstallOptions) -> Result<Vec<PathBuf>, InstallError> {
+        if opts.dry_run {
+            output::info(opts, "Would write the following files:");
+            // Only the payload's `usr/` tree is copied (into `/usr`), so
+            // list just those entries, at the path they end up at.
+            for entry in list_rpm_cpio(pkg)? {
+                let relative = entry.trim_start_matches("./");
+                if relative.starts_with("usr/") {
+                    println!("  /{}", relative);
+                }
+            }
+            return Ok(Vec::new());
+        }
+
+        if !output::confirm(opts, &format!("Install package '{}'?", pkg.display())) {
+            return Err(InstallError::Other("install aborted by user".to_string()));
+        }
+
+        let extract_dir = Path::new("/tmp/rpm_extract");
+        let target_dir = Path::new("/usr");
+
+        extract_rpm_cpio(pkg, extract_dir)?;
+
+        let install_dir = extract_dir.join("usr");
+        if !install_dir.exists() {
+            return Err(InstallError::Other(format!(
+                "could not find install directory: {:?}",
+                install_dir
+            )));
+        }
+
+        let mut installed_paths: Vec<PathBuf> = Vec::new();
+
+        for entry in fs::read_dir(&install_dir)? {
+            let entry = entry?;
+            let entry_path = entry.path();
+            let relative_path = entry_path.strip_prefix(&install_dir).unwrap();
+            let target_path = target_dir.join(relative_path);
+            copy_recursive(&entry_path, &target_path, opts, &mut installed_paths)?;
+        }
+
+        fs::remove_dir_all(extract_dir)?;
+
+        Ok(installed_paths)
+    }
+
+    fn metadata(&self, pkg: &Path) -> Result<PackageInfo, InstallError> {
+        // There is no RPM header parser yet, so the name is all that can be
+        // recovered without extracting the package.
+        let name = pkg
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_else(|| pkg.to_string_lossy().to_string());
+
+        Ok(PackageInfo {
+            name,
+            ..Default::default()
+        })
+    }
+}
+
+/// Copies `src` into `target`, recursing into subdirectories so nothing
+/// below the top level of the extracted cpio tree is silently skipped.
+/// Directories are merged into ones that already exist (and always walked);
+/// an individual file that already exists at `target` is left in place.
+fn copy_recursive(
+    src: &Path,
+    target: &Path,
+    opts: &InstallOptions,
+    installed_paths: &mut Vec<PathBuf>,
+) -> Result<(), InstallError> {
+    if src.is_dir() {
+        if !target.exists() {
+            fs::create_dir_all(target)?;
+            installed_paths.push(target.to_path_buf());
+        }
+        for entry in fs::read_dir(src)? {
+            let entry = entry?;
+            let child_target = target.join(entry.file_name());
+            copy_recursive(&entry.path(), &child_target, opts, installed_paths)?;
+        }
+        return Ok(());
+    }
+
+    if target.exists() {
+        output::warn(opts, format!("{:?} already exists, skipping.", target));
+        return Ok(());
+    }
+
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::copy(src, target)?;
+    installed_paths.push(target.to_path_buf());
+
+    Ok(())
+}
+
+/// Lists a `.rpm` payload's contents via `rpm2cpio | cpio -t`, without
+/// extracting anything, so `--dry-run` can report what would be written.
+fn list_rpm_cpio(rpm_path: &Path) -> Result<Vec<String>, InstallError> {
+    let rpm2cpio_output = Command::new("rpm2cpio")
+        .arg(rpm_path)
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| InstallError::Process(format!("failed to spawn rpm2cpio: {}", e)))?;
+
+    let cpio_output = Command::new("cpio")
+        .arg("-t")
+        .arg("--quiet")
+        .stdin(Stdio::from(rpm2cpio_output.stdout.unwrap()))
+        .output()
+        .map_err(|e| InstallError::Process(format!("failed to spawn cpio: {}", e)))?;
+
+    if !cpio_output.status.success() {
+        return Err(InstallError::Process("cpio listing failed".to_string()));
+    }
+
+    Ok(String::from_utf8_lossy(&cpio_output.stdout)
+        .lines()
+        .map(|s| s.to_string())
+        .collect())
+}
+
+/// Extracts a `.rpm` payload with `rpm2cpio | cpio`, same as the original
+/// standalone RPM installer did.
+fn extract_rpm_cpio(rpm_path: &Path, extract_to: &Path) -> Result<(), InstallError> {
+    fs::create_dir_all(extract_to)?;
+
+    let rpm2cpio_output = Command::new("rpm2cpio")
+        .arg(rpm_path)
+        .stdout(Stdio::piped())
+        .spawn()
+        .map_err(|e| InstallError::Process(format!("failed to spawn rpm2cpio: {}", e)))?;
+
+    let mut cpio = Command::new("cpio")
+        .arg("-idmv")
+        .current_dir(extract_to)
+        .stdin(Stdio::from(rpm2cpio_output.stdout.unwrap()))
+        .spawn()
+        .map_err(|e| InstallError::Process(format!("failed to spawn cpio: {}", e)))?;
+
+    let status = cpio
+        .wait()
+        .map_err(|e| InstallError::Process(format!("failed to wait on cpio: {}", e)))?;
+    if !status.success() {
+        return Err(InstallError::Process(
+            "cpio extraction failed".to_string(),
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,269 @@
+mod backend;
+mod backends;
+mod manifest;
+mod output;
+mod privilege;
+mod repository;
+
+use backend::{InstallOptions, PackageBackend};
+use backends::deb::DebBackend;
+use backends::eopkg::EopkgBackend;
+use backends::rpm::RpmBackend;
+use std::env;
+use std::path::{Path, PathBuf};
+
+/// Every registered backend, probed in order via `can_handle` instead of
+/// string-matching file extensions.
+fn backends() -> Vec<Box<dyn PackageBackend>> {
+    vec![
+        Box::new(DebBackend),
+        Box::new(EopkgBackend),
+        Box::new(RpmBackend),
+    ]
+}
+
+fn main() {
+    let raw_args: Vec<String> = env::args().skip(1).collect();
+
+    // Hidden entry point for the escalated child spawned by
+    // `privilege::escalate_install`: it only performs the file-writing
+    // install step and reports the written paths back over stdout.
+    if let Some(idx) = raw_args.iter().position(|a| a == "--write-phase") {
+        let package_path = raw_args
+            .get(idx + 1)
+            .unwrap_or_else(|| panic!("--write-phase requires a package path"));
+        let opts = parse_install_options(&raw_args);
+        run_write_phase(Path::new(package_path), &opts);
+        return;
+    }
+
+    // Hidden entry point for the escalated child spawned by
+    // `privilege::escalate_remove`: it only performs the filesystem
+    // deletion step.
+    if let Some(idx) = raw_args.iter().position(|a| a == "--remove-write-phase") {
+        let name = raw_args
+            .get(idx + 1)
+            .unwrap_or_else(|| panic!("--remove-write-phase requires a package name"));
+        let purge = raw_args.iter().any(|a| a == "--purge");
+        if let Err(e) = manifest::remove_package(name, purge) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let allow_root = raw_args.iter().any(|a| a == "--allow-root");
+    let opts = parse_install_options(&raw_args);
+    let args: Vec<String> = raw_args
+        .into_iter()
+        .filter(|a| !is_global_flag(a))
+        .collect();
+
+    match args.first().map(String::as_str) {
+        Some("install") => cmd_install(&args[1..], allow_root, &opts),
+        Some("remove") => cmd_remove(&args[1..], false, allow_root, &opts),
+        Some("purge") => cmd_remove(&args[1..], true, allow_root, &opts),
+        Some(other) => output::error(format!("Unknown command: {}", other)),
+        None => eprintln!(
+            "Usage: upkgt [--allow-root] [--noconfirm] [--verbose] [--dry-run] \
+             <install|remove|purge> <package>"
+        ),
+    }
+}
+
+fn is_global_flag(arg: &str) -> bool {
+    matches!(arg, "--allow-root" | "--noconfirm" | "--verbose" | "--dry-run")
+}
+
+fn parse_install_options(args: &[String]) -> InstallOptions {
+    let verbosity = 1 + args.iter().filter(|a| a.as_str() == "--verbose").count() as u8;
+    InstallOptions {
+        noconfirm: args.iter().any(|a| a == "--noconfirm"),
+        verbosity,
+        dry_run: args.iter().any(|a| a == "--dry-run"),
+    }
+}
+
+fn cmd_install(args: &[String], allow_root: bool, opts: &InstallOptions) {
+    // Refuse to even begin as root: no fetch, no confirmation prompt, until
+    // the caller explicitly opts in.
+    if privilege::is_root() && !allow_root {
+        output::error(
+            "refusing to install as root; re-run as a regular user, \
+             or pass --allow-root for container/chroot use.",
+        );
+        return;
+    }
+
+    let Some(package) = args.first() else {
+        output::error("missing required argument 'package'");
+        return;
+    };
+
+    // A bare package name (no matching local file) is resolved against the
+    // configured repositories and downloaded into the cache first.
+    let resolved_path: PathBuf = if Path::new(package).exists() {
+        PathBuf::from(package)
+    } else {
+        match repository::fetch_package(package) {
+            Ok(path) => path,
+            Err(e) => {
+                output::error(e);
+                return;
+            }
+        }
+    };
+    let package_path = resolved_path.as_path();
+
+    let Some(backend) = backends().into_iter().find(|b| b.can_handle(package_path)) else {
+        output::error(format!("unsupported package type: {}", package));
+        return;
+    };
+
+    let name = backend
+        .metadata(package_path)
+        .map(|info| info.name)
+        .unwrap_or_else(|_| package.to_string());
+
+    // Only eopkg packages declare RuntimeDependencies, so only they get a
+    // resolved multi-package plan; everything else installs as the single
+    // requested package, same as before.
+    let plan: Vec<(String, PathBuf)> = if backends::eopkg::looks_like_eopkg(package_path) {
+        match backends::eopkg::resolve_install_plan(package_path, &name) {
+            Ok(plan) => plan,
+            Err(e) => {
+                output::error(format!("resolving dependencies for '{}': {}", package, e));
+                return;
+            }
+        }
+    } else {
+        vec![(name.clone(), package_path.to_path_buf())]
+    };
+
+    // A dry run never writes anything, so it can run unprivileged without
+    // escalating or confirming at all.
+    if opts.dry_run {
+        for (_, path) in &plan {
+            if let Err(e) = backend.install(path, opts) {
+                output::error(format!("installing package '{}': {}", package, e));
+            }
+        }
+        return;
+    }
+
+    let prompt = if plan.len() > 1 {
+        let names: Vec<&str> = plan.iter().map(|(n, _)| n.as_str()).collect();
+        format!("Install package '{}' and its dependencies ({})?", name, names.join(", "))
+    } else {
+        format!("Install package '{}'?", name)
+    };
+    if !output::confirm(opts, &prompt) {
+        output::error("install aborted by user");
+        return;
+    }
+    // Already confirmed above, so the backend (and any escalated child)
+    // should not prompt again.
+    let mut confirmed_opts = opts.clone();
+    confirmed_opts.noconfirm = true;
+
+    for (pkg_name, pkg_path) in &plan {
+        let paths_result = if privilege::is_root() {
+            // Already explicitly allowed to run as root: no need to escalate.
+            backend
+                .install(pkg_path, &confirmed_opts)
+                .map_err(|e| e.to_string())
+        } else {
+            privilege::escalate_install(pkg_path, &confirmed_opts)
+        };
+
+        match paths_result {
+            Ok(paths) => {
+                if let Err(e) = manifest::record_install(pkg_name, &paths) {
+                    output::warn(
+                        opts,
+                        format!("failed to record installed-file manifest: {}", e),
+                    );
+                }
+            }
+            Err(e) => {
+                output::error(format!("installing package '{}': {}", pkg_name, e));
+                return;
+            }
+        }
+    }
+}
+
+fn cmd_remove(args: &[String], purge: bool, allow_root: bool, opts: &InstallOptions) {
+    // Refuse to even begin as root: no confirmation prompt, until the caller
+    // explicitly opts in. Mirrors the check at the top of cmd_install.
+    if privilege::is_root() && !allow_root {
+        output::error(
+            "refusing to remove as root; re-run as a regular user, \
+             or pass --allow-root for container/chroot use.",
+        );
+        return;
+    }
+
+    let Some(name) = args.first() else {
+        output::error("missing required argument 'name'");
+        return;
+    };
+
+    // A dry run never touches the filesystem, so it can run unprivileged
+    // without escalating or confirming at all.
+    if opts.dry_run {
+        match manifest::load(name) {
+            Ok(paths) => {
+                output::info(opts, "Would remove the following files:");
+                for path in &paths {
+                    println!("  {}", path.display());
+                }
+            }
+            Err(e) => output::error(format!(
+                "package '{}' has no recorded manifest: {}",
+                name, e
+            )),
+        }
+        return;
+    }
+
+    let verb = if purge { "Purge" } else { "Remove" };
+    if !output::confirm(opts, &format!("{} package '{}'?", verb, name)) {
+        output::error("remove aborted by user");
+        return;
+    }
+    // Already confirmed above, so the escalated child should not prompt
+    // again.
+    let mut confirmed_opts = opts.clone();
+    confirmed_opts.noconfirm = true;
+
+    let result = if privilege::is_root() {
+        // Already explicitly allowed to run as root: no need to escalate.
+        manifest::remove_package(name, purge)
+    } else {
+        privilege::escalate_remove(name, purge, &confirmed_opts)
+    };
+
+    if let Err(e) = result {
+        output::error(e);
+    }
+}
+
+fn run_write_phase(package_path: &Path, opts: &InstallOptions) {
+    let Some(backend) = backends().into_iter().find(|b| b.can_handle(package_path)) else {
+        eprintln!("Error: unsupported package type: {}", package_path.display());
+        std::process::exit(1);
+    };
+
+    match backend.install(package_path, opts) {
+        Ok(paths) => {
+            for path in paths {
+                println!("{}", path.display());
+            }
+        }
+        Err(e) => {
+            eprintln!("Error installing package: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Directory where per-package manifests are kept, mirroring where most
+/// package managers keep their installed-file state.
+const MANIFEST_DIR: &str = "/var/lib/upkgt/manifests";
+
+/// Rejects anything in `package_name` that could escape `MANIFEST_DIR`
+/// (e.g. a malicious `metadata.xml` declaring `Name` as `../../tmp/evil`),
+/// since the manifest it maps to is later read back by `remove_package`,
+/// whose deletions run as root.
+fn manifest_path(package_name: &str) -> io::Result<PathBuf> {
+    if package_name.is_empty() || package_name.contains('/') || package_name.contains("..") {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("invalid package name '{}'", package_name),
+        ));
+    }
+    Ok(Path::new(MANIFEST_DIR).join(format!("{}.manifest", package_name)))
+}
+
+/// Records the absolute paths written while installing `package_name`.
+///
+/// This must only be called after the install has fully succeeded: the
+/// caller accumulates paths in memory during extraction and commits them
+/// here in one shot, so a failed install never leaves a half-recorded
+/// package behind.
+pub fn record_install(package_name: &str, paths: &[PathBuf]) -> io::Result<()> {
+    fs::create_dir_all(MANIFEST_DIR)?;
+    let mut contents = String::new();
+    for path in paths {
+        contents.push_str(&path.to_string_lossy());
+        contents.push('\n');
+    }
+    fs::write(manifest_path(package_name)?, contents)
+}
+
+/// Loads the recorded paths for `package_name`, in install order.
+pub fn load(package_name: &str) -> io::Result<Vec<PathBuf>> {
+    let contents = fs::read_to_string(manifest_path(package_name)?)?;
+    Ok(contents.lines().map(PathBuf::from).collect())
+}
+
+fn drop_entry(package_name: &str) -> io::Result<()> {
+    let path = manifest_path(package_name)?;
+    if path.exists() {
+        fs::remove_file(path)?;
+    }
+    Ok(())
+}
+
+/// Deletes the files recorded for `package_name` in reverse install order
+/// (so a file is always removed before the directory that contains it),
+/// then drops the manifest entry.
+///
+/// Directories are only removed if they are empty, unless `force` is set
+/// (`purge`), in which case they are removed regardless of contents.
+pub fn remove_package(package_name: &str, force: bool) -> Result<(), String> {
+    let paths = load(package_name).map_err(|e| {
+        format!(
+            "Package '{}' has no recorded manifest: {}",
+            package_name, e
+        )
+    })?;
+
+    for path in paths.iter().rev() {
+        if !path.exists() {
+            continue;
+        }
+
+        if path.is_dir() {
+            let result = if force {
+                fs::remove_dir_all(path)
+            } else {
+                fs::remove_dir(path)
+            };
+            if let Err(e) = result {
+                if force {
+                    eprintln!(
+                        "Warning: failed to remove directory {}: {}",
+                        path.display(),
+                        e
+                    );
+                }
+                // a non-empty directory is left in place for a plain `remove`
+            }
+        } else if let Err(e) = fs::remove_file(path) {
+            eprintln!("Warning: failed to remove file {}: {}", path.display(), e);
+        }
+    }
+
+    drop_entry(package_name)
+        .map_err(|e| format!("Failed to drop manifest entry for '{}': {}", package_name, e))
+}
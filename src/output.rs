@@ -0,0 +1,53 @@
+use crate::backend::InstallOptions;
+use std::io::{self, Write};
+
+/// Verbosity tiers for [`info`]/[`verbose`]: 0 is quiet (errors only), 1 is
+/// the default, 2+ prints extra detail.
+const NORMAL: u8 = 1;
+const VERBOSE: u8 = 2;
+
+/// Prints a normal-priority message, suppressed at verbosity 0.
+pub fn info(opts: &InstallOptions, message: impl AsRef<str>) {
+    if opts.verbosity >= NORMAL {
+        println!("{}", message.as_ref());
+    }
+}
+
+/// Prints a message only shown under `--verbose`.
+pub fn verbose(opts: &InstallOptions, message: impl AsRef<str>) {
+    if opts.verbosity >= VERBOSE {
+        println!("{}", message.as_ref());
+    }
+}
+
+/// Prints a warning, suppressed at verbosity 0.
+pub fn warn(opts: &InstallOptions, message: impl AsRef<str>) {
+    if opts.verbosity >= NORMAL {
+        eprintln!("Warning: {}", message.as_ref());
+    }
+}
+
+/// Prints an error. Always shown, regardless of verbosity.
+pub fn error(message: impl AsRef<str>) {
+    eprintln!("Error: {}", message.as_ref());
+}
+
+/// Prompts for confirmation before a filesystem-mutating step, unless
+/// `--noconfirm` was passed.
+pub fn confirm(opts: &InstallOptions, prompt: &str) -> bool {
+    if opts.noconfirm {
+        return true;
+    }
+
+    print!("{} [y/N] ", prompt);
+    if io::stdout().flush().is_err() {
+        return false;
+    }
+
+    let mut answer = String::new();
+    if io::stdin().read_line(&mut answer).is_err() {
+        return false;
+    }
+
+    matches!(answer.trim().to_lowercase().as_str(), "y" | "yes")
+}
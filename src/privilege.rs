@@ -0,0 +1,97 @@
+use crate::backend::InstallOptions;
+use std::env;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+/// Whether the current process's effective UID is root.
+pub fn is_root() -> bool {
+    nix::unistd::Uid::effective().is_root()
+}
+
+/// Re-invokes the current binary's write phase under `pkexec` (falling back
+/// to `sudo` when `pkexec` isn't available), so everything up to this point
+/// — argument parsing, backend selection, metadata reads, the confirmation
+/// prompt — runs unprivileged, and only the actual file-writing install
+/// step runs as root.
+///
+/// The elevated child's stdout is piped back to parse the written paths,
+/// so it is always run with `--noconfirm` (the caller is expected to have
+/// confirmed already, before escalating) rather than prompting again in a
+/// process whose stdout isn't a terminal.
+///
+/// The elevated child is invoked with the hidden `--write-phase` flag and
+/// reports back the paths it wrote, one per line, on stdout. Lines there that
+/// are not absolute paths (the child's own progress messages) are ignored.
+pub fn escalate_install(package_path: &Path, opts: &InstallOptions) -> Result<Vec<PathBuf>, String> {
+    let exe = env::current_exe().map_err(|e| format!("failed to locate own executable: {}", e))?;
+    let escalation_tool = if which::which("pkexec").is_ok() {
+        "pkexec"
+    } else {
+        "sudo"
+    };
+
+    let mut command = Command::new(escalation_tool);
+    command.arg(&exe).arg("--write-phase").arg(package_path);
+    command.arg("--noconfirm");
+    for _ in 1..opts.verbosity {
+        command.arg("--verbose");
+    }
+
+    let output = command
+        .output()
+        .map_err(|e| format!("failed to escalate via {}: {}", escalation_tool, e))?;
+
+    if !output.status.success() {
+        return Err(format!(
+            "{} exited with {}: {}",
+            escalation_tool,
+            output.status,
+            String::from_utf8_lossy(&output.stderr)
+        ));
+    }
+
+    Ok(String::from_utf8_lossy(&output.stdout)
+        .lines()
+        .filter(|line| Path::new(line).is_absolute())
+        .map(PathBuf::from)
+        .collect())
+}
+
+/// Re-invokes the current binary's write phase under `pkexec`/`sudo` for a
+/// remove or purge, mirroring `escalate_install`: everything up to this
+/// point (argument parsing, the confirmation prompt) runs unprivileged, and
+/// only the actual filesystem deletion runs as root.
+///
+/// Unlike `escalate_install` there are no written paths to report back, so
+/// the elevated child's stdio is simply inherited rather than piped.
+pub fn escalate_remove(package_name: &str, purge: bool, opts: &InstallOptions) -> Result<(), String> {
+    let exe = env::current_exe().map_err(|e| format!("failed to locate own executable: {}", e))?;
+    let escalation_tool = if which::which("pkexec").is_ok() {
+        "pkexec"
+    } else {
+        "sudo"
+    };
+
+    let mut command = Command::new(escalation_tool);
+    command
+        .arg(&exe)
+        .arg("--remove-write-phase")
+        .arg(package_name);
+    if purge {
+        command.arg("--purge");
+    }
+    command.arg("--noconfirm");
+    for _ in 1..opts.verbosity {
+        command.arg("--verbose");
+    }
+
+    let status = command
+        .status()
+        .map_err(|e| format!("failed to escalate via {}: {}", escalation_tool, e))?;
+
+    if !status.success() {
+        return Err(format!("{} exited with {}", escalation_tool, status));
+    }
+
+    Ok(())
+}
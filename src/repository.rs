@@ -0,0 +1,161 @@
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+const REPOS_CONFIG_PATH: &str = "/etc/upkgt/repos.toml";
+
+#[derive(Debug, Deserialize, Default)]
+struct ReposConfig {
+    #[serde(rename = "repo", default)]
+    repos: Vec<RepoEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoEntry {
+    url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RepoIndex {
+    #[serde(default)]
+    packages: Vec<IndexEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct IndexEntry {
+    name: String,
+    file: String,
+    sha256: String,
+}
+
+/// Resolves a bare package name (e.g. `nano`) against the repositories
+/// configured in `/etc/upkgt/repos.toml`, downloading it into the cache
+/// directory if it isn't already there with a matching checksum.
+///
+/// Returns the path to the cached package file, ready to hand to the
+/// regular backend dispatch.
+pub fn fetch_package(name: &str) -> Result<PathBuf, String> {
+    let repos = load_repos()?;
+    if repos.is_empty() {
+        return Err(format!(
+            "'{}' is not a local file and no repositories are configured in {}",
+            name, REPOS_CONFIG_PATH
+        ));
+    }
+
+    let client = reqwest::blocking::Client::new();
+
+    for base_url in &repos {
+        let entry = match fetch_index_entry(&client, base_url, name) {
+            Ok(Some(entry)) => entry,
+            Ok(None) => continue,
+            Err(e) => {
+                eprintln!("Warning: failed to query repository {}: {}", base_url, e);
+                continue;
+            }
+        };
+
+        return download_cached(&client, base_url, &entry);
+    }
+
+    Err(format!(
+        "package '{}' was not found in any configured repository",
+        name
+    ))
+}
+
+fn load_repos() -> Result<Vec<String>, String> {
+    let contents = match fs::read_to_string(REPOS_CONFIG_PATH) {
+        Ok(contents) => contents,
+        Err(_) => return Ok(Vec::new()),
+    };
+    let config: ReposConfig = toml::from_str(&contents)
+        .map_err(|e| format!("failed to parse {}: {}", REPOS_CONFIG_PATH, e))?;
+    Ok(config.repos.into_iter().map(|r| r.url).collect())
+}
+
+fn fetch_index_entry(
+    client: &reqwest::blocking::Client,
+    base_url: &str,
+    name: &str,
+) -> Result<Option<IndexEntry>, String> {
+    let index_url = format!("{}/index.json", base_url.trim_end_matches('/'));
+    let index: RepoIndex = client
+        .get(&index_url)
+        .send()
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?
+        .json()
+        .map_err(|e| e.to_string())?;
+
+    Ok(index.packages.into_iter().find(|p| p.name == name))
+}
+
+fn download_cached(
+    client: &reqwest::blocking::Client,
+    base_url: &str,
+    entry: &IndexEntry,
+) -> Result<PathBuf, String> {
+    // The index is untrusted (fetched over the network); reject anything
+    // that could escape the cache directory before it's ever joined onto it.
+    if Path::new(&entry.file).is_absolute() || entry.file.contains("..") {
+        return Err(format!(
+            "repository index entry for '{}' has an unsafe file path: {}",
+            entry.name, entry.file
+        ));
+    }
+
+    let cache_path = cache_dir()?.join(&entry.file);
+
+    if cache_path.exists() && sha256_of(&cache_path)? == entry.sha256 {
+        return Ok(cache_path);
+    }
+
+    if let Some(parent) = cache_path.parent() {
+        fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let download_url = format!("{}/{}", base_url.trim_end_matches('/'), entry.file);
+    let bytes = client
+        .get(&download_url)
+        .send()
+        .map_err(|e| e.to_string())?
+        .error_for_status()
+        .map_err(|e| e.to_string())?
+        .bytes()
+        .map_err(|e| e.to_string())?;
+    fs::write(&cache_path, &bytes).map_err(|e| e.to_string())?;
+
+    let actual = sha256_of(&cache_path)?;
+    if actual != entry.sha256 {
+        let _ = fs::remove_file(&cache_path);
+        return Err(format!(
+            "checksum mismatch for '{}': expected {}, got {}",
+            entry.name, entry.sha256, actual
+        ));
+    }
+
+    Ok(cache_path)
+}
+
+fn sha256_of(path: &std::path::Path) -> Result<String, String> {
+    let mut file = fs::File::open(path).map_err(|e| e.to_string())?;
+    let mut hasher = Sha256::new();
+    let mut buffer = [0u8; 8192];
+    loop {
+        let n = file.read(&mut buffer).map_err(|e| e.to_string())?;
+        if n == 0 {
+            break;
+        }
+        hasher.update(&buffer[..n]);
+    }
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn cache_dir() -> Result<PathBuf, String> {
+    let home = std::env::var("HOME").map_err(|_| "HOME is not set".to_string())?;
+    Ok(PathBuf::from(home).join(".cache").join("upkgt"))
+}